@@ -0,0 +1,276 @@
+use crate::envelope::{AdsrStage, Envelope};
+
+/// Minimum time in seconds for attack, decay, and release to prevent division by zero.
+const MIN_TIME: f32 = 0.001;
+/// Minimum envelope level (silent).
+const MIN_LEVEL: f32 = 0.0;
+/// Maximum envelope level (full amplitude).
+const MAX_LEVEL: f32 = 1.0;
+/// How close the level must get to a segment's target before moving to the next stage.
+const EPSILON: f32 = 0.0001;
+/// Default attack overshoot target, giving the characteristic curved "knee" at the top.
+const DEFAULT_ATTACK_TARGET_RATIO: f32 = 1.2;
+/// Default release undershoot target, giving a snappier tail into silence.
+const DEFAULT_RELEASE_TARGET_RATIO: f32 = -0.05;
+
+/// Exponential (analog-style) ADSR envelope generator.
+///
+/// Unlike [`LinearAdsr`](crate::envelope::LinearAdsr), each segment approaches its
+/// target asymptotically rather than in a straight line, which is how the RC
+/// charge/discharge curves in analog envelope generators behave. This avoids the
+/// clicks that straight-line segments can produce on fast releases and gives
+/// attacks a natural curved "knee".
+///
+/// Each sample, the level moves toward a per-segment target:
+///
+/// ```text
+/// level += coeff * (target - level)
+/// ```
+///
+/// where `coeff = 1.0 - exp(-1.0 / (time_seconds * sample_rate))`. To get a
+/// curved attack that still reaches full scale, attack aims slightly above
+/// `1.0` (see [`Self::set_curve`]) and moves on to decay once the level
+/// reaches `1.0`. Decay aims at the sustain level, and release aims at `0.0`
+/// (or slightly below, for a snappier tail).
+///
+/// # Example
+///
+/// ```
+/// use soundlab::envelope::{Envelope, ExpAdsr};
+///
+/// let mut env = ExpAdsr::new(44100.0, 0.01, 0.1, 0.7, 0.3);
+/// env.gate_on();
+///
+/// for _ in 0..44100 {
+///     let amplitude = env.next_sample();
+///     // Multiply your oscillator output by amplitude
+/// }
+///
+/// env.gate_off(); // Start release phase
+/// ```
+pub struct ExpAdsr {
+    sample_rate: f32,
+    attack: f32,
+    decay: f32,
+    sustain: f32,
+    release: f32,
+    attack_coeff: f32,
+    decay_coeff: f32,
+    release_coeff: f32,
+    attack_target: f32,
+    release_target: f32,
+    stage: AdsrStage,
+    level: f32,
+    retrigger: bool,
+}
+
+impl ExpAdsr {
+    /// Creates a new exponential ADSR envelope.
+    ///
+    /// # Arguments
+    ///
+    /// * `sample_rate` - Sample rate in Hz (e.g., 44100.0)
+    /// * `attack` - Attack time in seconds
+    /// * `decay` - Decay time in seconds
+    /// * `sustain` - Sustain level from 0.0 to 1.0
+    /// * `release` - Release time in seconds
+    ///
+    /// Time values are clamped to a minimum of 1ms to prevent division by zero.
+    /// Sustain is clamped to the 0.0–1.0 range. Uses the default attack/release
+    /// curve (see [`Self::set_curve`]).
+    pub fn new(sample_rate: f32, attack: f32, decay: f32, sustain: f32, release: f32) -> Self {
+        let attack = attack.max(MIN_TIME);
+        let decay = decay.max(MIN_TIME);
+        let release = release.max(MIN_TIME);
+
+        let mut env = Self {
+            sample_rate,
+            attack,
+            decay,
+            sustain: sustain.clamp(MIN_LEVEL, MAX_LEVEL),
+            release,
+            attack_coeff: Self::coeff(attack, sample_rate),
+            decay_coeff: Self::coeff(decay, sample_rate),
+            release_coeff: Self::coeff(release, sample_rate),
+            attack_target: DEFAULT_ATTACK_TARGET_RATIO,
+            release_target: DEFAULT_RELEASE_TARGET_RATIO,
+            stage: AdsrStage::Idle,
+            level: MIN_LEVEL,
+            retrigger: true,
+        };
+        env.set_curve(1.0);
+        env
+    }
+
+    /// Creates a pad preset with slow attack and long release.
+    pub fn pad(sample_rate: f32) -> Self {
+        Self::new(sample_rate, 0.01, 0.1, 0.7, 0.3)
+    }
+
+    /// Creates a pluck preset with instant attack and no sustain.
+    pub fn pluck(sample_rate: f32) -> Self {
+        Self::new(sample_rate, 0.001, 0.3, 0.0, 0.1)
+    }
+
+    /// Creates a percussion preset with instant attack and fast decay.
+    pub fn percussion(sample_rate: f32) -> Self {
+        Self::new(sample_rate, 0.001, 0.1, 0.0, 0.05)
+    }
+
+    fn coeff(time_seconds: f32, sample_rate: f32) -> f32 {
+        1.0 - (-1.0 / (time_seconds * sample_rate)).exp()
+    }
+
+    /// Sets the attack time in seconds.
+    pub fn set_attack(&mut self, seconds: f32) {
+        self.attack = seconds.max(MIN_TIME);
+        self.attack_coeff = Self::coeff(self.attack, self.sample_rate);
+    }
+
+    /// Sets the decay time in seconds.
+    pub fn set_decay(&mut self, seconds: f32) {
+        self.decay = seconds.max(MIN_TIME);
+        self.decay_coeff = Self::coeff(self.decay, self.sample_rate);
+    }
+
+    /// Sets the sustain level (0.0 to 1.0).
+    pub fn set_sustain(&mut self, level: f32) {
+        self.sustain = level.clamp(MIN_LEVEL, MAX_LEVEL);
+    }
+
+    /// Sets the release time in seconds.
+    pub fn set_release(&mut self, seconds: f32) {
+        self.release = seconds.max(MIN_TIME);
+        self.release_coeff = Self::coeff(self.release, self.sample_rate);
+    }
+
+    /// Sets how curved the attack and release segments are.
+    ///
+    /// `curve` is clamped to `0.0..=1.0` (default `1.0`). It sets the attack
+    /// segment's overshoot target between `1.0` (at `0.0`) and `1.2` (at
+    /// `1.0`), and the release segment's undershoot target between `0.0` and
+    /// `-0.05`. A target further past a segment's end level reaches that level
+    /// on a straighter part of the exponential, so `0.0` gives the most curved
+    /// segments and `1.0` a slightly less pronounced "knee". No setting gives
+    /// near-linear segments.
+    pub fn set_curve(&mut self, curve: f32) {
+        let curve = curve.clamp(0.0, 1.0);
+        self.attack_target = 1.0 + curve * (DEFAULT_ATTACK_TARGET_RATIO - 1.0);
+        self.release_target = curve * DEFAULT_RELEASE_TARGET_RATIO;
+    }
+
+    /// Returns the current curve amount (0.0 to 1.0).
+    pub fn curve(&self) -> f32 {
+        let span = DEFAULT_ATTACK_TARGET_RATIO - 1.0;
+        if span == 0.0 {
+            0.0
+        } else {
+            (self.attack_target - 1.0) / span
+        }
+    }
+
+    /// Returns the attack time in seconds.
+    pub fn attack(&self) -> f32 {
+        self.attack
+    }
+
+    /// Returns the decay time in seconds.
+    pub fn decay(&self) -> f32 {
+        self.decay
+    }
+
+    /// Returns the sustain level.
+    pub fn sustain(&self) -> f32 {
+        self.sustain
+    }
+
+    /// Returns the release time in seconds.
+    pub fn release(&self) -> f32 {
+        self.release
+    }
+
+    /// Returns whether retrigger mode is enabled.
+    pub fn retrigger(&self) -> bool {
+        self.retrigger
+    }
+
+    /// Sets the retrigger behavior.
+    ///
+    /// When `true` (default), `gate_on` resets the level to zero before starting attack.
+    /// When `false`, the envelope continues from its current level (legato behavior).
+    pub fn set_retrigger(&mut self, retrigger: bool) {
+        self.retrigger = retrigger;
+    }
+
+    /// Returns the current envelope stage.
+    pub fn stage(&self) -> AdsrStage {
+        self.stage
+    }
+
+    /// Returns the current envelope level (0.0 to 1.0).
+    pub fn level(&self) -> f32 {
+        self.level
+    }
+}
+
+impl Envelope for ExpAdsr {
+    fn gate_on(&mut self) {
+        if self.retrigger {
+            self.level = MIN_LEVEL;
+        }
+        self.stage = if self.level >= MAX_LEVEL {
+            AdsrStage::Decay
+        } else {
+            AdsrStage::Attack
+        };
+    }
+
+    fn gate_off(&mut self) {
+        if self.stage != AdsrStage::Idle {
+            self.stage = AdsrStage::Release;
+        }
+    }
+
+    fn next_sample(&mut self) -> f32 {
+        match self.stage {
+            AdsrStage::Idle => {}
+
+            AdsrStage::Attack => {
+                self.level += self.attack_coeff * (self.attack_target - self.level);
+                if self.level >= MAX_LEVEL - EPSILON {
+                    self.level = MAX_LEVEL;
+                    self.stage = AdsrStage::Decay;
+                }
+            }
+
+            AdsrStage::Decay => {
+                self.level += self.decay_coeff * (self.sustain - self.level);
+                if (self.level - self.sustain).abs() <= EPSILON {
+                    self.level = self.sustain;
+                    self.stage = AdsrStage::Sustain;
+                }
+            }
+
+            AdsrStage::Sustain => {}
+
+            AdsrStage::Release => {
+                self.level += self.release_coeff * (self.release_target - self.level);
+                if self.level <= MIN_LEVEL + EPSILON {
+                    self.level = MIN_LEVEL;
+                    self.stage = AdsrStage::Idle;
+                }
+            }
+        }
+
+        self.level
+    }
+
+    fn is_active(&self) -> bool {
+        self.stage != AdsrStage::Idle
+    }
+
+    fn reset(&mut self) {
+        self.level = MIN_LEVEL;
+        self.stage = AdsrStage::Idle;
+    }
+}
@@ -3,8 +3,10 @@
 //! This module provides traits and implementations for audio envelopes,
 //! which control how a sound's amplitude changes over time.
 
+mod exp_adsr;
 mod linear_adsr;
 
+pub use exp_adsr::ExpAdsr;
 pub use linear_adsr::LinearAdsr;
 
 /// Trait for envelope generators.
@@ -0,0 +1,32 @@
+//! Resonant filter for subtractive synthesis.
+//!
+//! This module provides a [`Filter`] trait for audio-rate filters and [`Svf`],
+//! a state-variable filter giving simultaneous low-pass, high-pass, and
+//! band-pass outputs. Route a [`Voice`](crate::voice::Voice)'s modulation
+//! envelope to [`ModDestination::Cutoff`](crate::voice::ModDestination::Cutoff)
+//! for the classic filter-envelope sweep.
+
+mod svf;
+
+pub use svf::Svf;
+
+/// Trait for audio-rate filters.
+///
+/// A filter shapes a signal's frequency content one sample at a time,
+/// typically placed after an oscillator and before amplitude shaping in a
+/// voice's signal path.
+pub trait Filter {
+    /// Filters one input sample and returns the output.
+    ///
+    /// Call this once per sample in your audio processing loop.
+    fn process(&mut self, input: f32) -> f32;
+
+    /// Sets the cutoff frequency in Hz.
+    fn set_cutoff(&mut self, cutoff_hz: f32);
+
+    /// Sets the resonance (Q). Higher values narrow and emphasize the cutoff.
+    fn set_resonance(&mut self, resonance: f32);
+
+    /// Resets the filter's internal state to silence.
+    fn reset(&mut self);
+}
@@ -0,0 +1,127 @@
+use crate::filter::Filter;
+use std::f32::consts::PI;
+
+/// Minimum cutoff frequency in Hz, to keep `g` positive.
+const MIN_CUTOFF_HZ: f32 = 20.0;
+/// Minimum resonance, to prevent division by zero in `k = 1 / resonance`.
+const MIN_RESONANCE: f32 = 0.1;
+
+/// State-variable filter (SVF), TPT/Zavalishin topology.
+///
+/// Produces low-pass, band-pass, and high-pass outputs simultaneously from a
+/// single pair of integrators, each sample computing:
+///
+/// ```text
+/// v1 = (ic1eq + g * (x - ic2eq)) / (1 + g * (g + k))
+/// v2 = ic2eq + g * v1
+/// low = v2, band = v1, high = x - k * v1 - low
+/// ```
+///
+/// [`Filter::process`] returns the low-pass output; use [`Self::band`] and
+/// [`Self::high`] to read the other two from the same sample.
+///
+/// # Example
+///
+/// ```
+/// use soundlab::filter::{Filter, Svf};
+///
+/// let mut filter = Svf::new(44100.0, 1000.0, 0.7);
+///
+/// for sample in [0.0, 0.5, -0.3, 0.8] {
+///     let low_passed = filter.process(sample);
+///     let band_passed = filter.band();
+/// }
+/// ```
+pub struct Svf {
+    sample_rate: f32,
+    cutoff: f32,
+    resonance: f32,
+    g: f32,
+    k: f32,
+    ic1eq: f32,
+    ic2eq: f32,
+    low: f32,
+    band: f32,
+    high: f32,
+}
+
+impl Svf {
+    /// Creates a new state-variable filter at the given sample rate, cutoff
+    /// frequency in Hz, and resonance.
+    pub fn new(sample_rate: f32, cutoff_hz: f32, resonance: f32) -> Self {
+        let mut svf = Self {
+            sample_rate,
+            cutoff: 0.0,
+            resonance: 0.0,
+            g: 0.0,
+            k: 0.0,
+            ic1eq: 0.0,
+            ic2eq: 0.0,
+            low: 0.0,
+            band: 0.0,
+            high: 0.0,
+        };
+        svf.set_cutoff(cutoff_hz);
+        svf.set_resonance(resonance);
+        svf
+    }
+
+    /// Returns the cutoff frequency in Hz.
+    pub fn cutoff(&self) -> f32 {
+        self.cutoff
+    }
+
+    /// Returns the resonance.
+    pub fn resonance(&self) -> f32 {
+        self.resonance
+    }
+
+    /// Returns the low-pass output from the most recent [`Filter::process`] call.
+    pub fn low(&self) -> f32 {
+        self.low
+    }
+
+    /// Returns the band-pass output from the most recent [`Filter::process`] call.
+    pub fn band(&self) -> f32 {
+        self.band
+    }
+
+    /// Returns the high-pass output from the most recent [`Filter::process`] call.
+    pub fn high(&self) -> f32 {
+        self.high
+    }
+}
+
+impl Filter for Svf {
+    fn process(&mut self, input: f32) -> f32 {
+        let v1 = (self.ic1eq + self.g * (input - self.ic2eq)) / (1.0 + self.g * (self.g + self.k));
+        let v2 = self.ic2eq + self.g * v1;
+
+        self.ic1eq = 2.0 * v1 - self.ic1eq;
+        self.ic2eq = 2.0 * v2 - self.ic2eq;
+
+        self.band = v1;
+        self.low = v2;
+        self.high = input - self.k * v1 - self.low;
+
+        self.low
+    }
+
+    fn set_cutoff(&mut self, cutoff_hz: f32) {
+        self.cutoff = cutoff_hz.clamp(MIN_CUTOFF_HZ, self.sample_rate * 0.49);
+        self.g = (PI * self.cutoff / self.sample_rate).tan();
+    }
+
+    fn set_resonance(&mut self, resonance: f32) {
+        self.resonance = resonance.max(MIN_RESONANCE);
+        self.k = 1.0 / self.resonance;
+    }
+
+    fn reset(&mut self) {
+        self.ic1eq = 0.0;
+        self.ic2eq = 0.0;
+        self.low = 0.0;
+        self.band = 0.0;
+        self.high = 0.0;
+    }
+}
@@ -0,0 +1,23 @@
+/// Selects the routing graph connecting a [`FmOscillator`](super::FmOscillator)'s
+/// four operators: which operators modulate which, and which are summed to
+/// produce the audio output. Mirrors a handful of the canonical algorithms
+/// found on classic 4-operator FM synthesizers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Algorithm {
+    /// Operator 4 modulates 3, which modulates 2, which modulates 1 (output).
+    ///
+    /// A single deep modulation chain, good for metallic and bell-like tones.
+    #[default]
+    Stack4,
+    /// Two independent 2-operator stacks (4 modulates 3; 2 modulates 1),
+    /// summed to output. Good for layering two distinct timbres/ratios.
+    TwoStacks,
+    /// Operators 2, 3, and 4 all modulate operator 1 (output) in parallel.
+    ///
+    /// Additive modulators produce denser, more inharmonic spectra than a
+    /// single chain; a common choice for electric-piano and bass patches.
+    ThreeToOne,
+    /// All four operators output directly with no modulation (additive).
+    Parallel4,
+}
@@ -0,0 +1,155 @@
+use crate::envelope::Envelope;
+use crate::fm::{Algorithm, Operator};
+use oscy::Oscillator;
+
+/// Number of operators in an [`FmOscillator`].
+const OPERATOR_COUNT: usize = 4;
+
+/// A 4-operator FM synthesis oscillator.
+///
+/// Operators are sine phase-accumulators, each with its own envelope, combined
+/// through a selectable [`Algorithm`] describing which operators phase-modulate
+/// which, and which are summed to produce the output. This produces metallic,
+/// bell, bass, and electric-piano timbres that subtractive (saw/square)
+/// oscillators can't, and implements [`oscy::Oscillator`] so it can be used in
+/// place of one inside a [`Voice`](crate::voice::Voice). A `Voice`'s note
+/// events set the frequency but do not gate the operator envelopes; only
+/// [`Self::gate_on`] and [`Self::gate_off`] do.
+///
+/// # Example
+///
+/// ```
+/// use soundlab::envelope::LinearAdsr;
+/// use soundlab::fm::{Algorithm, FmOscillator, Operator};
+///
+/// let sample_rate = 44100.0;
+/// let operators = [
+///     Operator::new(sample_rate, 1.0, 0.0, 1.0, LinearAdsr::pad(sample_rate)),
+///     Operator::new(sample_rate, 2.0, 0.0, 0.8, LinearAdsr::pluck(sample_rate)),
+///     Operator::new(sample_rate, 3.0, 0.0, 0.5, LinearAdsr::pluck(sample_rate)),
+///     Operator::new(sample_rate, 7.0, 0.0, 0.3, LinearAdsr::percussion(sample_rate)),
+/// ];
+/// let mut fm = FmOscillator::new(Algorithm::Stack4, operators, 2.0);
+/// ```
+pub struct FmOscillator<E: Envelope> {
+    operators: [Operator<E>; OPERATOR_COUNT],
+    algorithm: Algorithm,
+    mod_index: f32,
+}
+
+impl<E: Envelope> FmOscillator<E> {
+    /// Creates a new FM oscillator from its four operators, routing algorithm,
+    /// and global modulation index (scales every modulator's output before it
+    /// reaches the operator(s) it feeds into).
+    pub fn new(
+        algorithm: Algorithm,
+        operators: [Operator<E>; OPERATOR_COUNT],
+        mod_index: f32,
+    ) -> Self {
+        Self {
+            operators,
+            algorithm,
+            mod_index,
+        }
+    }
+
+    /// Returns the routing algorithm.
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Sets the routing algorithm.
+    pub fn set_algorithm(&mut self, algorithm: Algorithm) {
+        self.algorithm = algorithm;
+    }
+
+    /// Returns the global modulation index.
+    pub fn mod_index(&self) -> f32 {
+        self.mod_index
+    }
+
+    /// Sets the global modulation index.
+    pub fn set_mod_index(&mut self, mod_index: f32) {
+        self.mod_index = mod_index;
+    }
+
+    /// Returns a reference to an operator by index (0-3).
+    pub fn operator(&self, index: usize) -> &Operator<E> {
+        &self.operators[index]
+    }
+
+    /// Returns a mutable reference to an operator by index (0-3), for
+    /// real-time editing of its ratio, level, envelope, or feedback.
+    pub fn operator_mut(&mut self, index: usize) -> &mut Operator<E> {
+        &mut self.operators[index]
+    }
+
+    /// Triggers all operator envelopes (note on).
+    pub fn gate_on(&mut self) {
+        for op in &mut self.operators {
+            op.gate_on();
+        }
+    }
+
+    /// Releases all operator envelopes (note off).
+    pub fn gate_off(&mut self) {
+        for op in &mut self.operators {
+            op.gate_off();
+        }
+    }
+
+    /// Returns `true` if any operator's envelope is still active.
+    pub fn is_active(&self) -> bool {
+        self.operators.iter().any(|op| op.is_active())
+    }
+}
+
+impl<E: Envelope> Oscillator for FmOscillator<E> {
+    fn set_frequency(&mut self, freq: f32) {
+        for op in &mut self.operators {
+            op.set_frequency(freq);
+        }
+    }
+
+    fn next_sample(&mut self) -> f32 {
+        match self.algorithm {
+            Algorithm::Stack4 => {
+                let o4 = self.operators[3].next_sample(0.0);
+                let o3 = self.operators[2].next_sample(o4 * self.mod_index);
+                let o2 = self.operators[1].next_sample(o3 * self.mod_index);
+                self.operators[0].next_sample(o2 * self.mod_index)
+            }
+
+            Algorithm::TwoStacks => {
+                let o4 = self.operators[3].next_sample(0.0);
+                let o3 = self.operators[2].next_sample(o4 * self.mod_index);
+                let o2 = self.operators[1].next_sample(0.0);
+                let o1 = self.operators[0].next_sample(o2 * self.mod_index);
+                (o3 + o1) * 0.5
+            }
+
+            Algorithm::ThreeToOne => {
+                let o4 = self.operators[3].next_sample(0.0);
+                let o3 = self.operators[2].next_sample(0.0);
+                let o2 = self.operators[1].next_sample(0.0);
+                let mod_sum = (o2 + o3 + o4) * self.mod_index;
+                self.operators[0].next_sample(mod_sum)
+            }
+
+            Algorithm::Parallel4 => {
+                let sum: f32 = self
+                    .operators
+                    .iter_mut()
+                    .map(|op| op.next_sample(0.0))
+                    .sum();
+                sum * 0.25
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        for op in &mut self.operators {
+            op.reset();
+        }
+    }
+}
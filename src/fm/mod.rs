@@ -0,0 +1,18 @@
+//! FM (frequency/phase modulation) synthesis.
+//!
+//! This module provides a 4-operator FM oscillator built from sine
+//! phase-accumulators, each with its own envelope, combined through a
+//! selectable routing [`Algorithm`]. It produces metallic, bell, bass, and
+//! electric-piano timbres that subtractive saw/square oscillators can't, and
+//! can be used anywhere an `oscy::Oscillator` is expected, including inside a
+//! [`Voice`](crate::voice::Voice). A `Voice` does not gate the operator
+//! envelopes on its note events; [`FmOscillator::gate_on`] and
+//! [`FmOscillator::gate_off`] do.
+
+mod algorithm;
+mod fm_oscillator;
+mod operator;
+
+pub use algorithm::Algorithm;
+pub use fm_oscillator::FmOscillator;
+pub use operator::Operator;
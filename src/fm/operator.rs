@@ -0,0 +1,166 @@
+use std::f32::consts::TAU;
+
+use crate::envelope::Envelope;
+
+/// A single FM operator: a sine phase-accumulator with its own envelope.
+///
+/// An operator's frequency is `ratio * note_frequency + detune`. When used as
+/// a modulator, its output (scaled by the containing [`FmOscillator`](super::FmOscillator)'s
+/// modulation index) is added to another operator's phase rather than summed
+/// to the audio output directly.
+pub struct Operator<E: Envelope> {
+    sample_rate: f32,
+    ratio: f32,
+    detune: f32,
+    level: f32,
+    envelope: E,
+    feedback: f32,
+    phase: f32,
+    phase_inc: f32,
+    note_freq: f32,
+    prev_output: f32,
+    prev_output2: f32,
+}
+
+impl<E: Envelope> Operator<E> {
+    /// Creates a new operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `ratio` - Frequency multiple relative to the note frequency (e.g. `1.0`, `2.0`)
+    /// * `detune` - Fixed frequency offset in Hz, applied after the ratio
+    /// * `level` - Output/total level (0.0 to 1.0)
+    /// * `envelope` - Per-operator envelope shaping this operator's level over time
+    pub fn new(sample_rate: f32, ratio: f32, detune: f32, level: f32, envelope: E) -> Self {
+        Self {
+            sample_rate,
+            ratio,
+            detune,
+            level,
+            envelope,
+            feedback: 0.0,
+            phase: 0.0,
+            phase_inc: 0.0,
+            note_freq: 0.0,
+            prev_output: 0.0,
+            prev_output2: 0.0,
+        }
+    }
+
+    /// Sets the note frequency this operator tracks, recomputing its phase increment.
+    pub fn set_frequency(&mut self, note_freq: f32) {
+        self.note_freq = note_freq;
+        self.recompute_phase_inc();
+    }
+
+    /// Recomputes `phase_inc` from the last-set note frequency, current
+    /// `ratio`, and current `detune`.
+    fn recompute_phase_inc(&mut self) {
+        let freq = (self.ratio * self.note_freq + self.detune).max(0.0);
+        self.phase_inc = freq / self.sample_rate;
+    }
+
+    /// Advances the operator by one sample given a phase modulation input
+    /// (the summed, modulation-index-scaled output of operators routed into
+    /// this one, or `0.0` for an unmodulated operator) and returns its output.
+    pub fn next_sample(&mut self, mod_input: f32) -> f32 {
+        let env_level = self.envelope.next_sample();
+        let feedback_input = self.feedback * (self.prev_output + self.prev_output2) * 0.5;
+        let out = self.level * env_level * (TAU * self.phase + mod_input + feedback_input).sin();
+
+        self.prev_output2 = self.prev_output;
+        self.prev_output = out;
+
+        self.phase += self.phase_inc;
+        if self.phase >= 1.0 {
+            self.phase -= 1.0;
+        }
+
+        out
+    }
+
+    /// Triggers the operator's envelope (note on).
+    pub fn gate_on(&mut self) {
+        self.envelope.gate_on();
+    }
+
+    /// Releases the operator's envelope (note off).
+    pub fn gate_off(&mut self) {
+        self.envelope.gate_off();
+    }
+
+    /// Resets the operator to its initial idle state.
+    pub fn reset(&mut self) {
+        self.envelope.reset();
+        self.phase = 0.0;
+        self.prev_output = 0.0;
+        self.prev_output2 = 0.0;
+    }
+
+    /// Returns the frequency ratio relative to the note frequency.
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    /// Sets the frequency ratio relative to the note frequency.
+    ///
+    /// Takes effect immediately, recomputing the phase increment from the
+    /// last-set note frequency rather than waiting for the next note-on.
+    pub fn set_ratio(&mut self, ratio: f32) {
+        self.ratio = ratio;
+        self.recompute_phase_inc();
+    }
+
+    /// Returns the detune offset in Hz.
+    pub fn detune(&self) -> f32 {
+        self.detune
+    }
+
+    /// Sets the detune offset in Hz.
+    ///
+    /// Takes effect immediately, recomputing the phase increment from the
+    /// last-set note frequency rather than waiting for the next note-on.
+    pub fn set_detune(&mut self, detune: f32) {
+        self.detune = detune;
+        self.recompute_phase_inc();
+    }
+
+    /// Returns the output/total level (0.0 to 1.0).
+    pub fn level(&self) -> f32 {
+        self.level
+    }
+
+    /// Sets the output/total level, clamped to `0.0..=1.0`.
+    pub fn set_level(&mut self, level: f32) {
+        self.level = level.clamp(0.0, 1.0);
+    }
+
+    /// Returns the self-feedback amount (0.0 to 1.0).
+    pub fn feedback(&self) -> f32 {
+        self.feedback
+    }
+
+    /// Sets the self-feedback amount, clamped to `0.0..=1.0`.
+    ///
+    /// Feedback mixes this operator's own previous output (averaged over the
+    /// last two samples, to stay stable) back into its phase.
+    pub fn set_feedback(&mut self, feedback: f32) {
+        self.feedback = feedback.clamp(0.0, 1.0);
+    }
+
+    /// Returns a reference to the operator's envelope.
+    pub fn envelope(&self) -> &E {
+        &self.envelope
+    }
+
+    /// Returns a mutable reference to the operator's envelope.
+    pub fn envelope_mut(&mut self) -> &mut E {
+        &mut self.envelope
+    }
+
+    /// Returns `true` if the operator's envelope is currently active.
+    pub fn is_active(&self) -> bool {
+        self.envelope.is_active()
+    }
+}
@@ -0,0 +1,228 @@
+//! Low-frequency oscillator for modulation.
+//!
+//! This module provides [`Lfo`], a low-frequency modulation source for
+//! effects like vibrato (pitch) and tremolo (amplitude). Unlike the
+//! audio-rate oscillators found elsewhere in the crate, an `Lfo` is meant to
+//! be sampled once per audio sample and used to modulate another parameter,
+//! not played back directly as sound.
+
+use std::f32::consts::TAU;
+
+/// Waveform shape produced by an [`Lfo`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LfoShape {
+    /// Smooth sine wave.
+    #[default]
+    Sine,
+    /// Linear triangle wave.
+    Triangle,
+    /// Rising sawtooth wave.
+    Saw,
+    /// Square wave (50% duty cycle).
+    Square,
+}
+
+/// Low-frequency oscillator, used as a modulation source rather than an
+/// audio-rate signal.
+///
+/// Produces `sine`, `triangle`, `saw`, or `square` shapes at a configurable
+/// rate and depth, in either unipolar (`0.0..=1.0`) or bipolar (`-1.0..=1.0`)
+/// polarity. Supports key-sync (resetting phase on note-on) and a delay/fade-in
+/// time before the LFO reaches full depth, so a vibrato can ease in after a
+/// note starts rather than being present from the first sample.
+///
+/// # Example
+///
+/// ```
+/// use soundlab::lfo::{Lfo, LfoShape};
+///
+/// let mut lfo = Lfo::new(44100.0, 5.0, LfoShape::Sine);
+/// lfo.gate_on(); // key-sync: reset phase and delay/fade-in
+///
+/// for _ in 0..44100 {
+///     let modulation = lfo.next_sample(); // bipolar -1.0..=1.0 by default
+/// }
+/// ```
+pub struct Lfo {
+    sample_rate: f32,
+    rate: f32,
+    depth: f32,
+    shape: LfoShape,
+    bipolar: bool,
+    key_sync: bool,
+    delay: f32,
+    fade_in: f32,
+    phase: f32,
+    phase_inc: f32,
+    age_samples: f32,
+}
+
+impl Lfo {
+    /// Creates a new LFO.
+    ///
+    /// # Arguments
+    ///
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `rate` - Modulation rate in Hz (e.g. `5.0` for a typical vibrato)
+    /// * `shape` - Waveform shape
+    ///
+    /// Defaults to bipolar output, full depth (`1.0`), key-sync enabled, and
+    /// no delay or fade-in.
+    pub fn new(sample_rate: f32, rate: f32, shape: LfoShape) -> Self {
+        Self {
+            sample_rate,
+            rate,
+            depth: 1.0,
+            shape,
+            bipolar: true,
+            key_sync: true,
+            delay: 0.0,
+            fade_in: 0.0,
+            phase: 0.0,
+            phase_inc: rate / sample_rate,
+            age_samples: 0.0,
+        }
+    }
+
+    /// Key-syncs the LFO: resets its phase and restarts the delay/fade-in,
+    /// as if a note had just been triggered.
+    ///
+    /// Has no effect if [`Self::set_key_sync`] has been disabled.
+    pub fn gate_on(&mut self) {
+        if self.key_sync {
+            self.phase = 0.0;
+            self.age_samples = 0.0;
+        }
+    }
+
+    /// Advances the LFO by one sample and returns the current modulation
+    /// value, scaled by depth and the delay/fade-in envelope.
+    ///
+    /// Returns a value in `-1.0..=1.0` if bipolar (the default), or
+    /// `0.0..=1.0` if unipolar.
+    pub fn next_sample(&mut self) -> f32 {
+        let raw = match self.shape {
+            LfoShape::Sine => (TAU * self.phase).sin(),
+            LfoShape::Triangle => {
+                4.0 * (self.phase - (self.phase + 0.75).floor() + 0.25).abs() - 1.0
+            }
+            LfoShape::Saw => 2.0 * self.phase - 1.0,
+            LfoShape::Square => {
+                if self.phase < 0.5 {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+        };
+
+        self.phase += self.phase_inc;
+        if self.phase >= 1.0 {
+            self.phase -= 1.0;
+        }
+
+        let fade = self.fade_envelope();
+        self.age_samples += 1.0;
+
+        if self.bipolar {
+            raw * self.depth * fade
+        } else {
+            ((raw * self.depth + self.depth) * 0.5) * fade
+        }
+    }
+
+    /// Returns the fade-in multiplier (0.0 to 1.0) for the current age,
+    /// accounting for the delay time before fade-in begins.
+    fn fade_envelope(&self) -> f32 {
+        let delay_samples = self.delay * self.sample_rate;
+        let fade_samples = self.fade_in * self.sample_rate;
+
+        if self.age_samples < delay_samples {
+            0.0
+        } else if fade_samples <= 0.0 {
+            1.0
+        } else {
+            ((self.age_samples - delay_samples) / fade_samples).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Returns the modulation rate in Hz.
+    pub fn rate(&self) -> f32 {
+        self.rate
+    }
+
+    /// Sets the modulation rate in Hz.
+    pub fn set_rate(&mut self, rate: f32) {
+        self.rate = rate;
+        self.phase_inc = rate / self.sample_rate;
+    }
+
+    /// Returns the modulation depth.
+    pub fn depth(&self) -> f32 {
+        self.depth
+    }
+
+    /// Sets the modulation depth, clamped to `0.0..=1.0`.
+    pub fn set_depth(&mut self, depth: f32) {
+        self.depth = depth.clamp(0.0, 1.0);
+    }
+
+    /// Returns the waveform shape.
+    pub fn shape(&self) -> LfoShape {
+        self.shape
+    }
+
+    /// Sets the waveform shape.
+    pub fn set_shape(&mut self, shape: LfoShape) {
+        self.shape = shape;
+    }
+
+    /// Returns `true` if the LFO outputs bipolar (`-1.0..=1.0`) values.
+    pub fn bipolar(&self) -> bool {
+        self.bipolar
+    }
+
+    /// Sets whether the LFO outputs bipolar (`-1.0..=1.0`, the default) or
+    /// unipolar (`0.0..=1.0`) values.
+    pub fn set_bipolar(&mut self, bipolar: bool) {
+        self.bipolar = bipolar;
+    }
+
+    /// Returns `true` if key-sync is enabled.
+    pub fn key_sync(&self) -> bool {
+        self.key_sync
+    }
+
+    /// Sets whether [`Self::gate_on`] resets the LFO's phase and delay/fade-in.
+    pub fn set_key_sync(&mut self, key_sync: bool) {
+        self.key_sync = key_sync;
+    }
+
+    /// Returns the delay time in seconds before fade-in begins.
+    pub fn delay(&self) -> f32 {
+        self.delay
+    }
+
+    /// Sets the delay time in seconds before fade-in begins.
+    pub fn set_delay(&mut self, seconds: f32) {
+        self.delay = seconds.max(0.0);
+    }
+
+    /// Returns the fade-in time in seconds.
+    pub fn fade_in(&self) -> f32 {
+        self.fade_in
+    }
+
+    /// Sets the fade-in time in seconds, over which the LFO ramps from zero
+    /// to full depth after the delay time has elapsed.
+    pub fn set_fade_in(&mut self, seconds: f32) {
+        self.fade_in = seconds.max(0.0);
+    }
+
+    /// Resets the LFO to its initial phase and delay/fade-in state.
+    pub fn reset(&mut self) {
+        self.phase = 0.0;
+        self.age_samples = 0.0;
+    }
+}
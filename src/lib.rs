@@ -15,7 +15,15 @@
 //! - [`envelope`] - Envelope generators (ADSR, etc.) for amplitude and modulation shaping
 //! - [`voice`] - Synthesizer voice combining oscillator and envelope
 //! - [`polyphony`] - Polyphonic voice allocation and management
+//! - [`fm`] - FM synthesis oscillator with operators, algorithms, and feedback
+//! - [`lfo`] - Low-frequency oscillator for vibrato/tremolo modulation
+//! - [`midi`] - MIDI message parsing
+//! - [`filter`] - Resonant state-variable filter for subtractive synthesis
 
 pub mod envelope;
+pub mod filter;
+pub mod fm;
+pub mod lfo;
+pub mod midi;
 pub mod voice;
 pub mod polyphony;
@@ -0,0 +1,130 @@
+//! MIDI message parsing.
+//!
+//! This module decodes raw MIDI byte sequences into a structured
+//! [`MidiMessage`], so callers don't have to hand-parse status and data bytes
+//! themselves. Pair it with [`Polyphony::handle_midi`](crate::polyphony::Polyphony::handle_midi)
+//! to drive a voice allocator directly from a MIDI input stream.
+
+/// A parsed MIDI channel message.
+///
+/// Channel numbers are zero-based (`0..=15`), matching the raw MIDI status
+/// byte's low nibble.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MidiMessage {
+    /// Note-on: a key was pressed with the given velocity (`1..=127`).
+    NoteOn {
+        /// MIDI channel (0-15).
+        channel: u8,
+        /// MIDI note number (0-127).
+        note: u8,
+        /// Note velocity (1-127).
+        velocity: u8,
+    },
+    /// Note-off: a key was released (or a note-on arrived with velocity `0`).
+    NoteOff {
+        /// MIDI channel (0-15).
+        channel: u8,
+        /// MIDI note number (0-127).
+        note: u8,
+        /// Release velocity (0-127); often unused.
+        velocity: u8,
+    },
+    /// Control change: a controller (e.g. mod wheel, sustain pedal) changed value.
+    ControlChange {
+        /// MIDI channel (0-15).
+        channel: u8,
+        /// Controller number (0-127).
+        controller: u8,
+        /// Controller value (0-127).
+        value: u8,
+    },
+    /// Pitch bend: the pitch wheel moved.
+    PitchBend {
+        /// MIDI channel (0-15).
+        channel: u8,
+        /// 14-bit bend value (0-16383), centered at `8192`.
+        value: u16,
+    },
+    /// Program change: a new patch/program was selected.
+    ProgramChange {
+        /// MIDI channel (0-15).
+        channel: u8,
+        /// Program number (0-127).
+        program: u8,
+    },
+}
+
+impl MidiMessage {
+    /// Parses a single MIDI channel message from raw bytes.
+    ///
+    /// `bytes` should start with a status byte (high bit set) followed by its
+    /// data bytes. Returns `None` if `bytes` is empty, doesn't start with a
+    /// status byte, is missing data bytes, or is a message type this crate
+    /// doesn't support (e.g. system exclusive).
+    ///
+    /// A note-on with velocity `0` is reported as [`MidiMessage::NoteOff`],
+    /// per the MIDI running-status convention.
+    pub fn from_bytes(bytes: &[u8]) -> Option<MidiMessage> {
+        let status = *bytes.first()?;
+        if status & 0x80 == 0 {
+            return None;
+        }
+
+        let channel = status & 0x0F;
+
+        match status & 0xF0 {
+            0x80 => {
+                let note = *bytes.get(1)?;
+                let velocity = *bytes.get(2)?;
+                Some(MidiMessage::NoteOff {
+                    channel,
+                    note,
+                    velocity,
+                })
+            }
+
+            0x90 => {
+                let note = *bytes.get(1)?;
+                let velocity = *bytes.get(2)?;
+                if velocity == 0 {
+                    Some(MidiMessage::NoteOff {
+                        channel,
+                        note,
+                        velocity: 0,
+                    })
+                } else {
+                    Some(MidiMessage::NoteOn {
+                        channel,
+                        note,
+                        velocity,
+                    })
+                }
+            }
+
+            0xB0 => {
+                let controller = *bytes.get(1)?;
+                let value = *bytes.get(2)?;
+                Some(MidiMessage::ControlChange {
+                    channel,
+                    controller,
+                    value,
+                })
+            }
+
+            0xE0 => {
+                let lsb = *bytes.get(1)?;
+                let msb = *bytes.get(2)?;
+                let value = ((msb as u16) << 7) | lsb as u16;
+                Some(MidiMessage::PitchBend { channel, value })
+            }
+
+            0xC0 => {
+                let program = *bytes.get(1)?;
+                Some(MidiMessage::ProgramChange { channel, program })
+            }
+
+            _ => None,
+        }
+    }
+}
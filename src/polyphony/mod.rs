@@ -2,12 +2,28 @@
 //!
 //! This module provides voice allocation and management for polyphonic synthesizers.
 //! It handles note-on/off events, voice stealing when all voices are busy, and
-//! mixing multiple voices into a single output.
+//! mixing multiple voices into a single output. Voice stealing fades the stolen
+//! voice out over a few milliseconds before the new note sounds, avoiding the
+//! click of jumping straight to a new frequency (see [`Voice::steal`]).
 
 use crate::envelope::Envelope;
-use crate::voice::Voice;
+use crate::midi::MidiMessage;
+use crate::voice::{Voice, DEFAULT_STEAL_FADE_SAMPLES};
 use oscy::Oscillator;
 
+/// MIDI CC number for the modulation wheel.
+const CC_MOD_WHEEL: u8 = 1;
+/// MIDI CC number for the sustain pedal.
+const CC_SUSTAIN: u8 = 64;
+/// MIDI CC number for "all sound off".
+const CC_ALL_SOUND_OFF: u8 = 120;
+/// MIDI CC number for "all notes off".
+const CC_ALL_NOTES_OFF: u8 = 123;
+/// Frequency offset in Hz produced by full pitch-bend travel in either direction.
+const PITCH_BEND_RANGE_HZ: f32 = 200.0;
+/// Center value of a 14-bit MIDI pitch-bend message.
+const PITCH_BEND_CENTER: f32 = 8192.0;
+
 /// Polyphonic voice manager.
 ///
 /// Manages a fixed number of voices for playing chords and handling note allocation.
@@ -60,6 +76,10 @@ pub struct Polyphony<O: Oscillator, E: Envelope, const N: usize> {
     /// Counter incremented on each note_on.
     counter: u64,
     steal_strategy: VoiceStealStrategy,
+    /// `true` while the sustain pedal (CC 64) is held.
+    sustain: bool,
+    /// Notes released while the sustain pedal was held, to release once it lifts.
+    sustained_notes: Vec<u8>,
 }
 
 impl<O: Oscillator, E: Envelope, const N: usize> Polyphony<O, E, N> {
@@ -70,6 +90,8 @@ impl<O: Oscillator, E: Envelope, const N: usize> Polyphony<O, E, N> {
             ages: [0; N],
             counter: 0,
             steal_strategy,
+            sustain: false,
+            sustained_notes: Vec::new(),
         }
     }
 
@@ -85,29 +107,36 @@ impl<O: Oscillator, E: Envelope, const N: usize> Polyphony<O, E, N> {
             ages: [0; N],
             counter: 0,
             steal_strategy,
+            sustain: false,
+            sustained_notes: Vec::new(),
         }
     }
 
     /// Triggers a note on a free voice, or steals one if all are busy.
+    ///
+    /// Stealing doesn't retrigger the voice immediately: to avoid an audible
+    /// click, the stolen voice is put into a short, fixed-length fade-out
+    /// (see [`Voice::steal`]) and `midi_note` sounds as soon as that
+    /// completes, which [`Self::next_sample`] drives.
     pub fn note_on(&mut self, midi_note: u8, velocity: f32) {
-        let voice_idx = self
-            .find_free_voice()
-            .unwrap_or_else(|| self.find_voice_to_steal());
-
         self.counter += 1;
+
+        if let Some(voice_idx) = self.find_free_voice() {
+            self.ages[voice_idx] = self.counter;
+            self.voices[voice_idx].note_on(midi_note, velocity);
+            return;
+        }
+
+        let voice_idx = self.find_voice_to_steal();
         self.ages[voice_idx] = self.counter;
-        self.voices[voice_idx].note_on(midi_note, velocity);
+        self.voices[voice_idx].steal(midi_note, velocity, DEFAULT_STEAL_FADE_SAMPLES);
     }
 
     /// Releases a note by finding the voice playing it.
     ///
     /// Does nothing if no voice is playing the given note.
     pub fn note_off(&mut self, midi_note: u8) {
-        if let Some(voice) = self
-            .voices
-            .iter_mut()
-            .find(|v| v.note() == Some(midi_note))
-        {
+        if let Some(voice) = self.voices.iter_mut().find(|v| v.note() == Some(midi_note)) {
             voice.note_off();
         }
     }
@@ -164,11 +193,90 @@ impl<O: Oscillator, E: Envelope, const N: usize> Polyphony<O, E, N> {
     fn find_free_voice(&self) -> Option<usize> {
         self.voices.iter().position(|v| !v.is_active())
     }
-    
+
     /// Returns the total number of voices.
     pub const fn capacity(&self) -> usize {
         N
     }
+
+    /// Dispatches a parsed MIDI channel message to the voice allocator.
+    ///
+    /// Handles note-on/off, the sustain pedal (CC 64, deferring note-offs
+    /// until it lifts), the mod wheel (CC 1, driving each voice's LFO depth),
+    /// pitch bend (applied to every voice so a bend affects a held chord
+    /// uniformly), and the all-sound-off/all-notes-off panic messages.
+    /// `ProgramChange` is ignored; this crate has no notion of programs.
+    ///
+    /// Pair with [`MidiMessage::from_bytes`] to drive this directly from a
+    /// raw MIDI input stream.
+    pub fn handle_midi(&mut self, message: MidiMessage) {
+        match message {
+            MidiMessage::NoteOn { note, velocity, .. } => {
+                self.note_on(note, velocity as f32 / 127.0);
+            }
+
+            MidiMessage::NoteOff { note, .. } => {
+                self.release_note(note);
+            }
+
+            MidiMessage::ControlChange {
+                controller, value, ..
+            } => match controller {
+                CC_MOD_WHEEL => {
+                    let depth = value as f32 / 127.0;
+                    for voice in &mut self.voices {
+                        if let Some(lfo) = voice.lfo_mut() {
+                            lfo.set_depth(depth);
+                        }
+                    }
+                }
+                CC_SUSTAIN => self.set_sustain(value >= 64),
+                CC_ALL_SOUND_OFF => self.reset(),
+                CC_ALL_NOTES_OFF => {
+                    for note in 0..=127 {
+                        self.release_note(note);
+                    }
+                }
+                _ => {}
+            },
+
+            MidiMessage::PitchBend { value, .. } => {
+                let offset_hz = (value as f32 - PITCH_BEND_CENTER) / PITCH_BEND_CENTER
+                    * PITCH_BEND_RANGE_HZ;
+                for voice in &mut self.voices {
+                    voice.set_pitch_bend_hz(offset_hz);
+                }
+            }
+
+            MidiMessage::ProgramChange { .. } => {}
+        }
+    }
+
+    /// Engages or disengages the sustain pedal.
+    ///
+    /// While engaged, notes released via [`Self::handle_midi`] keep sounding
+    /// instead of entering their envelope release; disengaging releases all
+    /// of them at once. Does not affect [`Self::note_off`] called directly.
+    pub fn set_sustain(&mut self, held: bool) {
+        self.sustain = held;
+        if !held {
+            let notes = std::mem::take(&mut self.sustained_notes);
+            for note in notes {
+                self.note_off(note);
+            }
+        }
+    }
+
+    /// Releases a note, or defers the release until the sustain pedal lifts.
+    fn release_note(&mut self, midi_note: u8) {
+        if self.sustain {
+            if !self.sustained_notes.contains(&midi_note) {
+                self.sustained_notes.push(midi_note);
+            }
+        } else {
+            self.note_off(midi_note);
+        }
+    }
 }
 
 /// Strategy for selecting which voice to steal when all voices are busy.
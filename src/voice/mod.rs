@@ -2,12 +2,29 @@
 //!
 //! A voice represents a single sound-producing unit, combining an oscillator
 //! with an amplitude envelope. Voices handle MIDI note events and generate
-//! audio samples.
+//! audio samples. A voice can optionally carry a second modulation envelope
+//! routed to a destination such as oscillator pitch (see [`ModRoute`]),
+//! enabling sounds like pitch-swept synth drums, and/or an [`Lfo`](crate::lfo::Lfo)
+//! routed to pitch or amplitude for vibrato/tremolo (see [`LfoDestination`]).
+//! A voice can also carry an [`Svf`] filter, placed after the oscillator and
+//! before amplitude shaping, with its cutoff sweepable by the modulation
+//! envelope (see [`ModDestination::Cutoff`]) for classic filter sweeps.
+//! [`Voice::steal`] lets an allocator like [`Polyphony`](crate::polyphony::Polyphony)
+//! retrigger a busy voice with a short click-free fade instead of jumping
+//! straight to the new note.
 
 use crate::envelope::Envelope;
+use crate::filter::{Filter, Svf};
+use crate::lfo::Lfo;
 use oscy::Oscillator;
 use pitchy::Pitch;
 
+/// Default duration of a [`Voice::steal`] fade-out, in samples.
+///
+/// A few milliseconds at typical audio sample rates, short enough to be
+/// inaudible as a fade but long enough to avoid a discontinuity click.
+pub const DEFAULT_STEAL_FADE_SAMPLES: u32 = 128;
+
 /// A single synthesizer voice combining an oscillator and amplitude envelope.
 ///
 /// The voice converts MIDI note numbers to frequencies, applies velocity scaling,
@@ -45,23 +62,95 @@ use pitchy::Pitch;
 pub struct Voice<O: Oscillator, E: Envelope> {
     osc: O,
     amp_env: E,
+    mod_env: Option<E>,
+    mod_route: Option<ModRoute>,
+    lfo: Option<Lfo>,
+    lfo_route: Option<LfoDestination>,
+    fast_fade: Option<FastFade>,
+    pending_note: Option<(u8, f32)>,
+    bend_offset_hz: f32,
+    bend_active: bool,
+    filter: Option<Svf>,
+    base_cutoff_hz: f32,
+    base_freq: f32,
     velocity: f32,
     note: Option<u8>,
 }
 
+/// Countdown for [`Voice::steal`]'s click-free fade-out.
+#[derive(Debug, Clone, Copy)]
+struct FastFade {
+    remaining: u32,
+    total: u32,
+}
+
 impl<O: Oscillator, E: Envelope> Voice<O, E> {
     /// Creates a new voice with the given oscillator and envelope.
     ///
-    /// The voice starts in an idle state with no note playing.
+    /// The voice starts in an idle state with no note playing, and has no
+    /// modulation envelope. Use [`Self::with_mod_env`] to add one.
     pub fn new(osc: O, amp_env: E) -> Self {
         Self {
             osc,
             amp_env,
+            mod_env: None,
+            mod_route: None,
+            lfo: None,
+            lfo_route: None,
+            fast_fade: None,
+            pending_note: None,
+            bend_offset_hz: 0.0,
+            bend_active: false,
+            filter: None,
+            base_cutoff_hz: 0.0,
+            base_freq: 0.0,
             velocity: 0.0,
             note: None,
         }
     }
 
+    /// Creates a new voice with both an amplitude envelope and a modulation
+    /// envelope routed to a destination such as oscillator pitch.
+    ///
+    /// The modulation envelope is gated alongside the amplitude envelope and
+    /// its output is applied to `destination` each sample (see [`ModRoute`]).
+    pub fn with_mod_env(osc: O, amp_env: E, mod_env: E, mod_route: ModRoute) -> Self {
+        Self {
+            osc,
+            amp_env,
+            mod_env: Some(mod_env),
+            mod_route: Some(mod_route),
+            lfo: None,
+            lfo_route: None,
+            fast_fade: None,
+            pending_note: None,
+            bend_offset_hz: 0.0,
+            bend_active: false,
+            filter: None,
+            base_cutoff_hz: 0.0,
+            base_freq: 0.0,
+            velocity: 0.0,
+            note: None,
+        }
+    }
+
+    /// Creates a percussion/kick voice: the modulation envelope sweeps the
+    /// oscillator frequency down from a high transient to the note's base
+    /// frequency, the classic synth-drum topology.
+    ///
+    /// `mod_env` should have a fast decay (tens of milliseconds) so the pitch
+    /// sweep is heard as a punchy transient rather than a sustained bend.
+    /// `amount_hz` is the extra frequency at the peak of the sweep, e.g. `200.0`
+    /// over a 50 Hz base note for a typical kick drum.
+    pub fn percussion(osc: O, amp_env: E, mod_env: E, amount_hz: f32) -> Self {
+        Self::with_mod_env(
+            osc,
+            amp_env,
+            mod_env,
+            ModRoute::new(ModDestination::Pitch { amount_hz }, 1.0),
+        )
+    }
+
     /// Triggers the voice with a MIDI note and velocity.
     ///
     /// Converts the MIDI note number to a frequency and starts the envelope.
@@ -90,8 +179,15 @@ impl<O: Oscillator, E: Envelope> Voice<O, E> {
 
         self.note = Some(midi_note);
         self.velocity = velocity.clamp(0.0, 1.0);
-        self.osc.set_frequency(pitch.frequency() as f32);
+        self.base_freq = pitch.frequency() as f32;
+        self.osc.set_frequency(self.base_freq);
         self.amp_env.gate_on();
+        if let Some(mod_env) = &mut self.mod_env {
+            mod_env.gate_on();
+        }
+        if let Some(lfo) = &mut self.lfo {
+            lfo.gate_on();
+        }
 
         Ok(())
     }
@@ -102,18 +198,143 @@ impl<O: Oscillator, E: Envelope> Voice<O, E> {
     /// Check [`Self::is_active`] to know when the voice has finished.
     pub fn note_off(&mut self) {
         self.amp_env.gate_off();
+        if let Some(mod_env) = &mut self.mod_env {
+            mod_env.gate_off();
+        }
         self.note = None;
     }
 
+    /// Steals the voice for a new note, fading out the currently playing
+    /// sound over `fade_samples` samples before triggering `midi_note` to
+    /// avoid the click of jumping straight to the new note's frequency.
+    ///
+    /// Forces the amplitude (and modulation) envelope into release, and
+    /// linearly ramps the voice's output to silence independent of however
+    /// long that release actually takes. [`Self::next_sample`] triggers the
+    /// stored note automatically once the fade completes.
+    ///
+    /// If this voice is already mid-steal (i.e. a previous [`Self::steal`]
+    /// call is still waiting to retrigger), that pending note is sounded
+    /// immediately first rather than silently discarded, and is left
+    /// unreleased under the new fade, so every note this is called with
+    /// eventually sounds at least briefly before this one takes over.
+    pub fn steal(&mut self, midi_note: u8, velocity: f32, fade_samples: u32) {
+        let resumed_pending = match self.pending_note.take() {
+            Some((pending_note, pending_velocity)) => {
+                self.note_on(pending_note, pending_velocity);
+                true
+            }
+            None => false,
+        };
+
+        let fade_samples = fade_samples.max(1);
+        self.pending_note = Some((midi_note, velocity));
+        self.fast_fade = Some(FastFade {
+            remaining: fade_samples,
+            total: fade_samples,
+        });
+        if !resumed_pending {
+            self.amp_env.gate_off();
+            if let Some(mod_env) = &mut self.mod_env {
+                mod_env.gate_off();
+            }
+        }
+    }
+
+    /// Returns `true` if the voice is currently fading out before a stolen note retriggers.
+    pub fn is_stealing(&self) -> bool {
+        self.fast_fade.is_some()
+    }
+
     /// Generates and returns the next audio sample.
     ///
     /// Call this once per sample in your audio processing loop.
     /// Returns 0.0 when the voice is idle.
     pub fn next_sample(&mut self) -> f32 {
+        let mut freq_offset = 0.0;
+        let mut retune = false;
+        let mut amp_mod = 1.0;
+        let mut cutoff_offset = 0.0;
+        let mut retune_filter = false;
+
+        if let (Some(mod_env), Some(mod_route)) = (&mut self.mod_env, &self.mod_route) {
+            let mod_level = mod_env.next_sample() * mod_route.depth;
+            match mod_route.destination {
+                ModDestination::Pitch { amount_hz } => {
+                    freq_offset += mod_level * amount_hz;
+                    retune = true;
+                }
+                ModDestination::Cutoff { amount_hz } => {
+                    cutoff_offset += mod_level * amount_hz;
+                    retune_filter = true;
+                }
+            }
+        }
+
+        if let Some(lfo) = &mut self.lfo {
+            let lfo_level = lfo.next_sample();
+            match self.lfo_route {
+                Some(LfoDestination::Pitch { amount_hz }) => {
+                    freq_offset += lfo_level * amount_hz;
+                    retune = true;
+                }
+                Some(LfoDestination::Amplitude { amount }) => {
+                    amp_mod = 1.0 + lfo_level * amount;
+                }
+                None => {}
+            }
+        }
+
+        if self.bend_active {
+            freq_offset += self.bend_offset_hz;
+            retune = true;
+        }
+
+        if retune {
+            self.osc.set_frequency(self.base_freq + freq_offset);
+        }
+
+        if retune_filter {
+            if let Some(filter) = &mut self.filter {
+                filter.set_cutoff(self.base_cutoff_hz + cutoff_offset);
+            }
+        }
+
         let osc_out = self.osc.next_sample();
+        let filtered_out = match &mut self.filter {
+            Some(filter) => filter.process(osc_out),
+            None => osc_out,
+        };
         let env_out = self.amp_env.next_sample();
+        let fade_gain = self.advance_fast_fade();
 
-        osc_out * env_out * self.velocity
+        filtered_out * env_out * self.velocity * amp_mod * fade_gain
+    }
+
+    /// Advances the steal fade-out countdown, if one is active, triggering
+    /// the pending note once it completes. Returns the current fade gain
+    /// (`1.0` when no fade is in progress).
+    fn advance_fast_fade(&mut self) -> f32 {
+        let (remaining, total) = match &self.fast_fade {
+            Some(fade) => (fade.remaining, fade.total),
+            None => return 1.0,
+        };
+
+        let gain = remaining as f32 / total as f32;
+
+        if remaining == 0 {
+            self.fast_fade = None;
+            if let Some((note, velocity)) = self.pending_note.take() {
+                self.note_on(note, velocity);
+            }
+        } else {
+            self.fast_fade = Some(FastFade {
+                remaining: remaining - 1,
+                total,
+            });
+        }
+
+        gain
     }
 
     /// Returns `true` if the voice is currently producing sound.
@@ -130,6 +351,17 @@ impl<O: Oscillator, E: Envelope> Voice<O, E> {
     /// Useful for voice stealing or panic/all-notes-off handling.
     pub fn reset(&mut self) {
         self.amp_env.reset();
+        if let Some(mod_env) = &mut self.mod_env {
+            mod_env.reset();
+        }
+        if let Some(lfo) = &mut self.lfo {
+            lfo.reset();
+        }
+        if let Some(filter) = &mut self.filter {
+            filter.reset();
+        }
+        self.fast_fade = None;
+        self.pending_note = None;
         self.osc.reset();
         self.velocity = 0.0;
         self.note = None;
@@ -168,4 +400,200 @@ impl<O: Oscillator, E: Envelope> Voice<O, E> {
     pub fn amp_env_mut(&mut self) -> &mut E {
         &mut self.amp_env
     }
+
+    /// Returns a reference to the modulation envelope, if one is configured.
+    pub fn mod_env(&self) -> Option<&E> {
+        self.mod_env.as_ref()
+    }
+
+    /// Returns a mutable reference to the modulation envelope, if one is configured.
+    ///
+    /// Use this to change the modulation envelope's parameters in real-time.
+    pub fn mod_env_mut(&mut self) -> Option<&mut E> {
+        self.mod_env.as_mut()
+    }
+
+    /// Sets or clears the modulation envelope.
+    ///
+    /// Has no effect on the routing destination or depth; use [`Self::set_mod_route`]
+    /// to change those.
+    pub fn set_mod_env(&mut self, mod_env: Option<E>) {
+        self.mod_env = mod_env;
+    }
+
+    /// Returns the current modulation routing, if one is configured.
+    pub fn mod_route(&self) -> Option<ModRoute> {
+        self.mod_route
+    }
+
+    /// Sets or clears the modulation routing.
+    pub fn set_mod_route(&mut self, mod_route: Option<ModRoute>) {
+        self.mod_route = mod_route;
+    }
+
+    /// Returns a reference to the LFO, if one is configured.
+    pub fn lfo(&self) -> Option<&Lfo> {
+        self.lfo.as_ref()
+    }
+
+    /// Returns a mutable reference to the LFO, if one is configured.
+    ///
+    /// Use this to change the LFO's rate, depth, shape, or delay/fade-in in real-time.
+    pub fn lfo_mut(&mut self) -> Option<&mut Lfo> {
+        self.lfo.as_mut()
+    }
+
+    /// Sets or clears the LFO.
+    ///
+    /// Has no effect on the routing destination; use [`Self::set_lfo_route`] to change that.
+    pub fn set_lfo(&mut self, lfo: Option<Lfo>) {
+        self.lfo = lfo;
+    }
+
+    /// Returns the current LFO routing, if one is configured.
+    pub fn lfo_route(&self) -> Option<LfoDestination> {
+        self.lfo_route
+    }
+
+    /// Sets or clears the LFO routing.
+    pub fn set_lfo_route(&mut self, lfo_route: Option<LfoDestination>) {
+        self.lfo_route = lfo_route;
+    }
+
+    /// Returns the current pitch-bend frequency offset in Hz.
+    pub fn pitch_bend_hz(&self) -> f32 {
+        self.bend_offset_hz
+    }
+
+    /// Sets the pitch-bend frequency offset in Hz, added on top of the note's
+    /// base frequency and any other pitch modulation. Pass `0.0` to center.
+    ///
+    /// Typically driven from a MIDI pitch-bend message; see
+    /// [`Polyphony::handle_midi`](crate::polyphony::Polyphony::handle_midi).
+    pub fn set_pitch_bend_hz(&mut self, offset_hz: f32) {
+        self.bend_offset_hz = offset_hz;
+        self.bend_active = true;
+    }
+
+    /// Returns a reference to the filter, if one is configured.
+    pub fn filter(&self) -> Option<&Svf> {
+        self.filter.as_ref()
+    }
+
+    /// Returns a mutable reference to the filter, if one is configured.
+    ///
+    /// Use this to change resonance in real-time; prefer [`Self::set_base_cutoff_hz`]
+    /// for cutoff so sweeps from the modulation envelope keep working.
+    pub fn filter_mut(&mut self) -> Option<&mut Svf> {
+        self.filter.as_mut()
+    }
+
+    /// Sets or clears the filter.
+    ///
+    /// Placed in the signal path after the oscillator and before amplitude
+    /// shaping. Has no effect on the base cutoff or routing; use
+    /// [`Self::set_base_cutoff_hz`] and [`Self::set_mod_route`] for those.
+    pub fn set_filter(&mut self, filter: Option<Svf>) {
+        self.filter = filter;
+        if let Some(filter) = &mut self.filter {
+            filter.set_cutoff(self.base_cutoff_hz);
+        }
+    }
+
+    /// Returns the filter's base cutoff frequency in Hz.
+    pub fn base_cutoff_hz(&self) -> f32 {
+        self.base_cutoff_hz
+    }
+
+    /// Sets the filter's base cutoff frequency in Hz, the center the
+    /// modulation envelope sweeps around when routed to
+    /// [`ModDestination::Cutoff`].
+    pub fn set_base_cutoff_hz(&mut self, cutoff_hz: f32) {
+        self.base_cutoff_hz = cutoff_hz;
+        if let Some(filter) = &mut self.filter {
+            filter.set_cutoff(cutoff_hz);
+        }
+    }
+}
+
+/// Destination for a [`ModRoute`]'s modulation envelope.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum ModDestination {
+    /// Modulates oscillator frequency, added on top of the note's base frequency.
+    ///
+    /// `amount_hz` is the frequency offset at full modulation envelope level
+    /// and depth, e.g. `200.0` for a kick-drum pitch sweep.
+    Pitch {
+        /// Frequency offset in Hz at full modulation level.
+        amount_hz: f32,
+    },
+    /// Modulates filter cutoff, added on top of [`Voice::base_cutoff_hz`].
+    ///
+    /// `amount_hz` is the cutoff offset at full modulation envelope level and
+    /// depth, e.g. a few kHz for a classic filter-sweep pluck. Has no effect
+    /// on a voice with no filter configured (see [`Voice::set_filter`]).
+    Cutoff {
+        /// Cutoff offset in Hz at full modulation level.
+        amount_hz: f32,
+    },
+}
+
+/// Routes a voice's modulation envelope to a destination at a given depth.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModRoute {
+    destination: ModDestination,
+    depth: f32,
+}
+
+impl ModRoute {
+    /// Creates a new modulation route.
+    ///
+    /// `depth` scales the modulation envelope's level before it reaches the
+    /// destination, and is clamped to `0.0..=1.0`.
+    pub fn new(destination: ModDestination, depth: f32) -> Self {
+        Self {
+            destination,
+            depth: depth.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Returns the modulation destination.
+    pub fn destination(&self) -> ModDestination {
+        self.destination
+    }
+
+    /// Returns the modulation depth (0.0 to 1.0).
+    pub fn depth(&self) -> f32 {
+        self.depth
+    }
+
+    /// Sets the modulation depth, clamped to `0.0..=1.0`.
+    pub fn set_depth(&mut self, depth: f32) {
+        self.depth = depth.clamp(0.0, 1.0);
+    }
+}
+
+/// Destination for a voice's [`Lfo`] modulation source.
+///
+/// Unlike [`ModRoute`], depth is configured on the [`Lfo`] itself (see
+/// [`Lfo::set_depth`](crate::lfo::Lfo::set_depth)) rather than on the route.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum LfoDestination {
+    /// Modulates oscillator frequency (vibrato), added on top of the note's base frequency.
+    ///
+    /// `amount_hz` is the frequency offset at full LFO depth.
+    Pitch {
+        /// Frequency offset in Hz at full LFO depth.
+        amount_hz: f32,
+    },
+    /// Modulates output amplitude (tremolo).
+    ///
+    /// The voice's output is scaled by `1.0 + lfo_value * amount`, so `amount`
+    /// controls how deep the tremolo dips below (and peaks above) unity gain.
+    Amplitude {
+        /// Amplitude scaling amount at full LFO depth.
+        amount: f32,
+    },
 }